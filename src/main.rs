@@ -1,8 +1,18 @@
-use chrono::{DateTime, Local, TimeZone, Utc};
-use clap::Parser;
+use chrono::{DateTime, Local, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use clap::{Parser, Subcommand};
+use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::Sha256;
+use std::collections::hash_map::DefaultHasher;
 use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const LOW_RATE_LIMIT_THRESHOLD: i64 = 10;
 
 #[derive(Debug, Deserialize, Serialize)]
 struct Workflow {
@@ -22,6 +32,8 @@ struct WorkflowResponse {
 #[derive(Debug, Deserialize, Serialize)]
 struct WorkflowRun {
     created_at: String,
+    updated_at: String,
+    run_started_at: String,
     status: String,
     conclusion: Option<String>,
 }
@@ -32,6 +44,19 @@ struct WorkflowRunsResponse {
     workflow_runs: Vec<WorkflowRun>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+struct CacheEntry {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    link: Option<String>,
+    body: Value,
+}
+
+struct CachedResponse {
+    body: Value,
+    link: Option<String>,
+}
+
 /// Simple program to fetch and display GitHub workflows
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -42,9 +67,56 @@ struct Args {
     /// Repository name
     #[arg(short, long)]
     repo: String,
+    /// Cap the number of workflows fetched (default: no cap)
+    #[arg(short, long)]
+    limit: Option<usize>,
+    /// Only consider runs on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    since: Option<String>,
+    /// Only consider runs on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    until: Option<String>,
+    /// Render timestamps in this IANA timezone (e.g. America/New_York, UTC)
+    /// instead of the host's local timezone
+    #[arg(short = 'z', long)]
+    timezone: Option<Tz>,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    format: OutputFormat,
+    /// Filter workflows with a boolean expression over name/state/created_at/updated_at,
+    /// e.g. `state = "active" AND name CONTAINS "deploy"`
+    #[arg(long)]
+    filter: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-fn parse_github_timestamp_to_local(timestamp: &str) -> Result<DateTime<Local>, chrono::ParseError> {
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+enum OutputFormat {
+    /// Emoji-decorated human report
+    Pretty,
+    /// A single JSON array of enriched workflows
+    Json,
+    /// One JSON object per line
+    Ndjson,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Start a webhook server that prints a status line for each verified `workflow_run`
+    /// event; it does not print or update the workflow summary
+    Serve {
+        /// Port to listen for GitHub webhook deliveries on
+        #[arg(short, long, default_value_t = 8080)]
+        port: u16,
+        /// Shared secret used to verify the `X-Hub-Signature-256` header
+        #[arg(long, env = "GHGLIM_WEBHOOK_SECRET")]
+        secret: String,
+    },
+}
+
+fn parse_github_timestamp(timestamp: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
     // First parse as UTC, then convert to local
     let utc_dt = DateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.3fZ")
         .or_else(|_| DateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%SZ"))
@@ -58,57 +130,608 @@ fn parse_github_timestamp_to_local(timestamp: &str) -> Result<DateTime<Local>, c
             DateTime::parse_from_str(&normalized, "%Y-%m-%dT%H:%M:%S%z")
         })?;
 
-    // Convert to UTC first, then to local
-    let utc_datetime = Utc.from_utc_datetime(&utc_dt.naive_utc());
-    Ok(utc_datetime.with_timezone(&Local))
+    Ok(Utc.from_utc_datetime(&utc_dt.naive_utc()))
 }
 
-fn get_last_run_date(
+#[derive(Debug, Clone, Copy)]
+enum OutputZone {
+    Local,
+    Named(Tz),
+}
+
+impl OutputZone {
+    fn from_arg(timezone: Option<Tz>) -> Self {
+        match timezone {
+            Some(tz) => OutputZone::Named(tz),
+            None => OutputZone::Local,
+        }
+    }
+
+    fn format(&self, utc: DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            OutputZone::Local => utc.with_timezone(&Local).format(fmt).to_string(),
+            OutputZone::Named(tz) => utc.with_timezone(tz).format(fmt).to_string(),
+        }
+    }
+}
+
+type DateRange = (DateTime<Utc>, DateTime<Utc>);
+
+// DST fall-back picks the earliest instant; a spring-forward gap nudges forward a minute at a time.
+fn local_datetime_to_utc(naive: NaiveDateTime) -> DateTime<Utc> {
+    let local_dt = match Local.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            let mut candidate = naive;
+            loop {
+                candidate += chrono::Duration::minutes(1);
+                if let LocalResult::Single(dt) = Local.from_local_datetime(&candidate) {
+                    break dt;
+                }
+            }
+        }
+    };
+    local_dt.with_timezone(&Utc)
+}
+
+fn try_create_range(date: &str) -> Result<DateRange, Box<dyn Error>> {
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d")?;
+    let start_naive = day.and_hms_opt(0, 0, 0).ok_or("invalid start of day")?;
+    let end_naive = (day + chrono::Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .ok_or("invalid end of day")?;
+
+    Ok((
+        local_datetime_to_utc(start_naive),
+        local_datetime_to_utc(end_naive),
+    ))
+}
+
+fn resolve_date_range(
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Result<Option<DateRange>, Box<dyn Error>> {
+    match (since, until) {
+        (None, None) => Ok(None),
+        (Some(since), None) => {
+            let (start, _) = try_create_range(since)?;
+            Ok(Some((start, Utc::now())))
+        }
+        (None, Some(until)) => {
+            let (_, end) = try_create_range(until)?;
+            Ok(Some((DateTime::<Utc>::MIN_UTC, end)))
+        }
+        (Some(since), Some(until)) => {
+            let (start, _) = try_create_range(since)?;
+            let (_, end) = try_create_range(until)?;
+            Ok(Some((start, end)))
+        }
+    }
+}
+
+fn format_run_duration(elapsed_ms: i64) -> String {
+    let millis = elapsed_ms.rem_euclid(1000);
+    let total_seconds = elapsed_ms / 1000;
+    let seconds = total_seconds % 60;
+    let total_minutes = total_seconds / 60;
+    let minutes = total_minutes % 60;
+    let hours = total_minutes / 60;
+
+    let units = [(hours, "h"), (minutes, "m"), (seconds, "s"), (millis, "ms")];
+    let mut found = units.into_iter().filter(|(value, _)| *value > 0).take(2);
+
+    match (found.next(), found.next()) {
+        (Some((value, unit)), None) => format!("{}{}", value, unit),
+        (Some((seconds, "s")), Some((millis, "ms"))) => format!("{}.{:02}s", seconds, millis / 10),
+        (Some((first_value, first_unit)), Some((second_value, second_unit))) => {
+            format!("{}{}{}{}", first_value, first_unit, second_value, second_unit)
+        }
+        (None, _) => "0ms".to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FilterOp {
+    Eq,
+    Ne,
+    Contains,
+    Gt,
+    Lt,
+}
+
+#[derive(Debug, Clone)]
+struct FilterClause {
+    field: String,
+    op: FilterOp,
+    value: String,
+}
+
+struct FilterExpr {
+    or_groups: Vec<Vec<FilterClause>>,
+}
+
+fn parse_filter_clause(raw: &str) -> Result<FilterClause, Box<dyn Error>> {
+    const OPS: [(&str, FilterOp); 5] = [
+        ("!=", FilterOp::Ne),
+        ("CONTAINS", FilterOp::Contains),
+        ("=", FilterOp::Eq),
+        (">", FilterOp::Gt),
+        ("<", FilterOp::Lt),
+    ];
+
+    let raw = raw.trim();
+    // The operator that actually starts earliest wins, not the one type-priority
+    // checks first - otherwise a quoted value containing e.g. "CONTAINS" can be
+    // mistaken for the clause's own operator.
+    let leftmost = OPS
+        .iter()
+        .filter_map(|(token, op)| raw.find(token).map(|idx| (idx, *token, *op)))
+        .min_by_key(|(idx, token, _)| (*idx, std::cmp::Reverse(token.len())));
+
+    let Some((idx, token, op)) = leftmost else {
+        return Err(format!("could not parse filter clause: {}", raw).into());
+    };
+
+    let field = raw[..idx].trim().to_string();
+    let value = raw[idx + token.len()..].trim().trim_matches('"').to_string();
+    if field.is_empty() || value.is_empty() {
+        return Err(format!("could not parse filter clause: {}", raw).into());
+    }
+
+    Ok(FilterClause { field, op, value })
+}
+
+// Splits on `sep` everywhere it appears outside of a `"..."` value, so a
+// quoted value containing the literal word "AND"/"OR" isn't split on.
+fn split_outside_quotes<'a>(expr: &'a str, sep: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut i = 0;
+    while i < expr.len() {
+        if expr[i..].starts_with('"') {
+            in_quotes = !in_quotes;
+        }
+        if !in_quotes && expr[i..].starts_with(sep) {
+            parts.push(&expr[start..i]);
+            i += sep.len();
+            start = i;
+            continue;
+        }
+        i += 1;
+    }
+    parts.push(&expr[start..]);
+    parts
+}
+
+fn parse_filter(expr: &str) -> Result<FilterExpr, Box<dyn Error>> {
+    let or_groups = split_outside_quotes(expr, " OR ")
+        .into_iter()
+        .map(|and_group| {
+            split_outside_quotes(and_group, " AND ")
+                .into_iter()
+                .map(parse_filter_clause)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(FilterExpr { or_groups })
+}
+
+fn parse_filter_timestamp(value: &str) -> Result<DateTime<Utc>, Box<dyn Error>> {
+    if let Ok(dt) = parse_github_timestamp(value) {
+        return Ok(dt);
+    }
+    let (start, _) = try_create_range(value)?;
+    Ok(start)
+}
+
+fn eval_filter_clause(clause: &FilterClause, workflow: &Workflow) -> bool {
+    match clause.field.as_str() {
+        "name" => match clause.op {
+            FilterOp::Eq => workflow.name == clause.value,
+            FilterOp::Ne => workflow.name != clause.value,
+            FilterOp::Contains => workflow.name.contains(&clause.value),
+            FilterOp::Gt | FilterOp::Lt => false,
+        },
+        "state" => match clause.op {
+            FilterOp::Eq => workflow.state == clause.value,
+            FilterOp::Ne => workflow.state != clause.value,
+            FilterOp::Contains => workflow.state.contains(&clause.value),
+            FilterOp::Gt | FilterOp::Lt => false,
+        },
+        "created_at" | "updated_at" => {
+            let field_value = if clause.field == "created_at" {
+                &workflow.created_at
+            } else {
+                &workflow.updated_at
+            };
+            let (Ok(field_ts), Ok(clause_ts)) = (
+                parse_github_timestamp(field_value),
+                parse_filter_timestamp(&clause.value),
+            ) else {
+                return false;
+            };
+            match clause.op {
+                FilterOp::Eq => field_ts == clause_ts,
+                FilterOp::Ne => field_ts != clause_ts,
+                FilterOp::Gt => field_ts > clause_ts,
+                FilterOp::Lt => field_ts < clause_ts,
+                FilterOp::Contains => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+impl FilterExpr {
+    fn matches(&self, workflow: &Workflow) -> bool {
+        self.or_groups
+            .iter()
+            .any(|and_group| and_group.iter().all(|clause| eval_filter_clause(clause, workflow)))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct EnrichedRun {
+    created_at: String,
+    status: String,
+    conclusion: Option<String>,
+    duration_ms: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct EnrichedWorkflow {
+    id: u64,
+    name: String,
+    state: String,
+    created_at: String,
+    updated_at: String,
+    last_run: Option<EnrichedRun>,
+    runs_in_range: Option<Vec<EnrichedRun>>,
+}
+
+fn enrich_run(run: WorkflowRun) -> EnrichedRun {
+    let duration_ms = if run.status == "completed" {
+        match (
+            parse_github_timestamp(&run.run_started_at),
+            parse_github_timestamp(&run.updated_at),
+        ) {
+            (Ok(started), Ok(ended)) => Some((ended - started).num_milliseconds()),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    EnrichedRun {
+        created_at: run.created_at,
+        status: run.status,
+        conclusion: run.conclusion,
+        duration_ms,
+    }
+}
+
+fn enrich_workflow(
+    client: &reqwest::blocking::Client,
+    owner: &str,
+    repo: &str,
+    workflow: &Workflow,
+    range: Option<DateRange>,
+) -> Result<EnrichedWorkflow, Box<dyn Error>> {
+    let (last_run, runs_in_range) = match range {
+        Some(range) => {
+            let runs = get_runs_in_range(client, owner, repo, workflow.id, range)?;
+            (None, Some(runs.into_iter().map(enrich_run).collect()))
+        }
+        None => (get_last_run(client, owner, repo, workflow.id)?.map(enrich_run), None),
+    };
+
+    Ok(EnrichedWorkflow {
+        id: workflow.id,
+        name: workflow.name.clone(),
+        state: workflow.state.clone(),
+        created_at: workflow.created_at.clone(),
+        updated_at: workflow.updated_at.clone(),
+        last_run,
+        runs_in_range,
+    })
+}
+
+fn output_structured(
+    workflows: &[Workflow],
+    client: &reqwest::blocking::Client,
+    owner: &str,
+    repo: &str,
+    range: Option<DateRange>,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        OutputFormat::Ndjson => {
+            for workflow in workflows {
+                let enriched = enrich_workflow(client, owner, repo, workflow, range)?;
+                println!("{}", serde_json::to_string(&enriched)?);
+            }
+        }
+        OutputFormat::Json => {
+            let mut enriched = Vec::with_capacity(workflows.len());
+            for workflow in workflows {
+                enriched.push(enrich_workflow(client, owner, repo, workflow, range)?);
+            }
+            println!("{}", serde_json::to_string_pretty(&enriched)?);
+        }
+        OutputFormat::Pretty => unreachable!("pretty output is handled by display_workflows"),
+    }
+
+    Ok(())
+}
+
+fn get_runs_in_range(
     client: &reqwest::blocking::Client,
     owner: &str,
     repo: &str,
     workflow_id: u64,
-) -> Result<Option<String>, Box<dyn Error>> {
-    let response = client
-        .get(format!(
-            "https://api.github.com/repos/{}/{}/actions/workflows/{}/runs?per_page=1",
-            owner, repo, workflow_id
-        ))
-        .header("User-Agent", "MyApp/1.0")
-        .send()?;
-
-    if response.status().is_success() {
-        let json: Value = response.json()?;
-        let runs_response: WorkflowRunsResponse = serde_json::from_value(json)?;
-
-        if let Some(last_run) = runs_response.workflow_runs.first() {
-            Ok(Some(last_run.created_at.clone()))
-        } else {
-            Ok(None)
+    range: DateRange,
+) -> Result<Vec<WorkflowRun>, Box<dyn Error>> {
+    let (start, end) = range;
+    let url = format!(
+        "https://api.github.com/repos/{}/{}/actions/workflows/{}/runs?per_page=100&created={}..{}",
+        owner,
+        repo,
+        workflow_id,
+        start.to_rfc3339(),
+        end.to_rfc3339()
+    );
+
+    let cached = match get_with_cache(client, &url) {
+        Ok(cached) => cached,
+        Err(e) => {
+            eprintln!("⚠️  Failed to fetch runs for workflow {}: {}", workflow_id, e);
+            return Ok(Vec::new());
         }
-    } else {
-        println!(
-            "⚠️  Failed to fetch runs for workflow {}: {}",
-            workflow_id,
-            response.status()
-        );
+    };
+    let runs_response: WorkflowRunsResponse = serde_json::from_value(cached.body)?;
+
+    let runs_in_range = runs_response
+        .workflow_runs
+        .into_iter()
+        .filter(|run| run_created_in_range(run, (start, end)))
+        .collect();
+
+    Ok(runs_in_range)
+}
+
+fn run_created_in_range(run: &WorkflowRun, range: DateRange) -> bool {
+    let (start, end) = range;
+    parse_github_timestamp(&run.created_at)
+        .map(|created_utc| created_utc >= start && created_utc < end)
+        .unwrap_or(false)
+}
+
+fn get_last_run(
+    client: &reqwest::blocking::Client,
+    owner: &str,
+    repo: &str,
+    workflow_id: u64,
+) -> Result<Option<WorkflowRun>, Box<dyn Error>> {
+    let url = format!(
+        "https://api.github.com/repos/{}/{}/actions/workflows/{}/runs?per_page=1",
+        owner, repo, workflow_id
+    );
+
+    let cached = match get_with_cache(client, &url) {
+        Ok(cached) => cached,
+        Err(e) => {
+            eprintln!("⚠️  Failed to fetch runs for workflow {}: {}", workflow_id, e);
+            return Ok(None);
+        }
+    };
+
+    let mut runs_response: WorkflowRunsResponse = serde_json::from_value(cached.body)?;
+
+    if runs_response.workflow_runs.is_empty() {
         Ok(None)
+    } else {
+        Ok(Some(runs_response.workflow_runs.remove(0)))
     }
 }
 
+fn parse_next_link(link_header: &str) -> Option<String> {
+    link_header.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url_part = segments.next()?.trim();
+        let is_next = segments.any(|s| s.trim() == "rel=\"next\"");
+        is_next.then(|| url_part.trim_start_matches('<').trim_end_matches('>').to_string())
+    })
+}
+
+fn cache_dir() -> PathBuf {
+    // Per-user where the platform has a cache dir: a predictable path in the
+    // shared world-writable temp dir would let other local users poison or read
+    // cached responses. Without a per-user cache dir it falls back to the temp dir.
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("ghglim")
+}
+
+fn cache_path_for_url(url: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    cache_dir().join(format!("{:016x}.json", hasher.finish()))
+}
+
+fn load_cache_entry(url: &str) -> Option<CacheEntry> {
+    let bytes = std::fs::read(cache_path_for_url(url)).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+fn save_cache_entry(url: &str, entry: &CacheEntry) {
+    let path = cache_path_for_url(url);
+    if std::fs::create_dir_all(cache_dir()).is_err() {
+        return;
+    }
+    if let Ok(bytes) = serde_json::to_vec(entry) {
+        let _ = std::fs::write(path, bytes);
+    }
+}
+
+fn warn_on_low_rate_limit(headers: &reqwest::header::HeaderMap) {
+    let remaining = headers
+        .get("X-RateLimit-Remaining")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<i64>().ok());
+
+    let Some(remaining) = remaining else {
+        return;
+    };
+    if remaining > LOW_RATE_LIMIT_THRESHOLD {
+        return;
+    }
+
+    let reset_at = headers
+        .get("X-RateLimit-Reset")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<i64>().ok())
+        .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0))
+        .map(|dt| dt.format("%m-%d-%Y at %H:%M UTC").to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    eprintln!(
+        "⚠️  Only {} GitHub API requests remaining until reset at {}",
+        remaining, reset_at
+    );
+}
+
+fn get_with_cache(client: &reqwest::blocking::Client, url: &str) -> Result<CachedResponse, Box<dyn Error>> {
+    let cached = load_cache_entry(url);
+
+    let mut request = client.get(url).header("User-Agent", "MyApp/1.0");
+    if let Some(entry) = &cached {
+        if let Some(etag) = &entry.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag.clone());
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified.clone());
+        }
+    }
+
+    let response = request.send()?;
+    warn_on_low_rate_limit(response.headers());
+
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        let entry = cached.ok_or("received 304 Not Modified but no cache entry exists")?;
+
+        if let Some(last_modified) = &entry.last_modified {
+            if let Ok(freshness) = DateTime::parse_from_rfc2822(last_modified) {
+                eprintln!(
+                    "📦 Using cached response (fresh as of {})",
+                    freshness.format("%m-%d-%Y at %H:%M %z")
+                );
+            }
+        }
+
+        return Ok(CachedResponse {
+            body: entry.body,
+            link: entry.link,
+        });
+    }
+
+    if !response.status().is_success() {
+        return Err(format!("request to {} failed with status {}", url, response.status()).into());
+    }
+
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let last_modified = response
+        .headers()
+        .get(reqwest::header::LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let link = response
+        .headers()
+        .get(reqwest::header::LINK)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
+    let body: Value = response.json()?;
+
+    save_cache_entry(
+        url,
+        &CacheEntry {
+            etag,
+            last_modified,
+            link: link.clone(),
+            body: body.clone(),
+        },
+    );
+
+    Ok(CachedResponse { body, link })
+}
+
+fn fetch_all_workflows(
+    client: &reqwest::blocking::Client,
+    owner: &str,
+    repo: &str,
+    limit: Option<usize>,
+) -> Result<Vec<Workflow>, Box<dyn Error>> {
+    let mut workflows = Vec::new();
+    let mut url = format!(
+        "https://api.github.com/repos/{}/{}/actions/workflows?per_page=100&page=1",
+        owner, repo
+    );
+
+    loop {
+        let cached = match get_with_cache(client, &url) {
+            Ok(cached) => cached,
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                break;
+            }
+        };
+
+        let next_url = cached.link.as_deref().and_then(parse_next_link);
+        let page: WorkflowResponse = serde_json::from_value(cached.body)?;
+
+        let page_was_empty = page.workflows.is_empty();
+        workflows.extend(page.workflows);
+
+        if let Some(limit) = limit {
+            if workflows.len() >= limit {
+                workflows.truncate(limit);
+                break;
+            }
+        }
+
+        if page_was_empty || workflows.len() as i32 >= page.total_count {
+            break;
+        }
+
+        match next_url {
+            Some(next) => url = next,
+            None => break,
+        }
+    }
+
+    Ok(workflows)
+}
+
 fn display_workflows(
-    json: &Value,
+    workflows: &[Workflow],
     client: &reqwest::blocking::Client,
     owner: &str,
     repo: &str,
+    range: Option<DateRange>,
+    zone: OutputZone,
 ) -> Result<(), Box<dyn Error>> {
-    let response: WorkflowResponse = serde_json::from_value(json.clone())?;
-
     println!("🔧 GitHub Workflows Summary");
     println!("═══════════════════════════");
-    println!("📊 Total workflows: {}\n", response.total_count);
+    println!("📊 Total workflows: {}\n", workflows.len());
 
-    for (index, workflow) in response.workflows.iter().enumerate() {
+    for (index, workflow) in workflows.iter().enumerate() {
         println!("🚀 Workflow #{}", index + 1);
         println!("📝 Name: {}", workflow.name);
 
@@ -120,10 +743,10 @@ fn display_workflows(
         };
         println!("{} State: {}", state_emoji, workflow.state);
 
-        // Parse and format created date in local time
-        match parse_github_timestamp_to_local(&workflow.created_at) {
+        // Parse and format created date in the requested zone
+        match parse_github_timestamp(&workflow.created_at) {
             Ok(created_dt) => {
-                println!("🎉 Created: {}", created_dt.format("%m-%d-%Y at %H:%M"))
+                println!("🎉 Created: {}", zone.format(created_dt, "%m-%d-%Y at %H:%M"))
             }
             Err(_) => {
                 println!("🎉 Created: {} (raw format)", workflow.created_at);
@@ -141,11 +764,11 @@ fn display_workflows(
             if is_active { "Yes ✅" } else { "No ❌" }
         );
 
-        // Parse and format updated date in local time
-        match parse_github_timestamp_to_local(&workflow.updated_at) {
+        // Parse and format updated date in the requested zone
+        match parse_github_timestamp(&workflow.updated_at) {
             Ok(updated_dt) => println!(
                 "📅 Last Updated: {}",
-                updated_dt.format("%m-%d-%Y at %H:%M")
+                zone.format(updated_dt, "%m-%d-%Y at %H:%M")
             ),
             Err(_) => {
                 println!("📅 Last Updated: {} (raw format)", workflow.updated_at);
@@ -156,20 +779,51 @@ fn display_workflows(
             }
         }
 
-        print!("🏃 Last Run: ");
-        match get_last_run_date(client, owner, repo, workflow.id) {
-            Ok(Some(last_run_date)) => match parse_github_timestamp_to_local(&last_run_date) {
-                Ok(run_dt) => println!("{}", run_dt.format("%m-%d-%Y at %H:%M")),
-                Err(_) => {
-                    println!("{} (raw format)", last_run_date);
-                    eprintln!(
-                        "⚠️  Could not parse last run date format: {}",
-                        last_run_date
-                    );
+        if let Some(range) = range {
+            match get_runs_in_range(client, owner, repo, workflow.id, range) {
+                Ok(runs) if runs.is_empty() => println!("🏃 Runs in range: none"),
+                Ok(runs) => {
+                    println!("🏃 Runs in range: {}", runs.len());
+                    for run in &runs {
+                        let when = parse_github_timestamp(&run.created_at)
+                            .map(|dt| zone.format(dt, "%m-%d-%Y at %H:%M"))
+                            .unwrap_or_else(|_| format!("{} (raw format)", run.created_at));
+                        println!(
+                            "   - {} [{}]",
+                            when,
+                            run.conclusion.as_deref().unwrap_or(&run.status)
+                        );
+                    }
                 }
-            },
-            Ok(None) => println!("Never run ⏸️"),
-            Err(e) => println!("Error fetching run data: {} ❌", e),
+                Err(e) => println!("🏃 Error fetching runs in range: {} ❌", e),
+            }
+        } else {
+            print!("🏃 Last Run: ");
+            match get_last_run(client, owner, repo, workflow.id) {
+                Ok(Some(last_run)) => {
+                    match parse_github_timestamp(&last_run.created_at) {
+                        Ok(run_dt) => println!("{}", zone.format(run_dt, "%m-%d-%Y at %H:%M")),
+                        Err(_) => {
+                            println!("{} (raw format)", last_run.created_at);
+                            eprintln!(
+                                "⚠️  Could not parse last run date format: {}",
+                                last_run.created_at
+                            );
+                        }
+                    }
+
+                    if last_run.status == "completed" {
+                        let started = parse_github_timestamp(&last_run.run_started_at);
+                        let ended = parse_github_timestamp(&last_run.updated_at);
+                        if let (Ok(started), Ok(ended)) = (started, ended) {
+                            let elapsed_ms = (ended - started).num_milliseconds();
+                            println!("⏱ Duration: {}", format_run_duration(elapsed_ms));
+                        }
+                    }
+                }
+                Ok(None) => println!("Never run ⏸️"),
+                Err(e) => println!("Error fetching run data: {} ❌", e),
+            }
         }
 
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
@@ -178,30 +832,280 @@ fn display_workflows(
     Ok(())
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args = Args::parse();
-    let client = reqwest::blocking::Client::new();
+fn verify_github_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
+    let hex_sig = match signature_header.strip_prefix("sha256=") {
+        Some(hex_sig) => hex_sig,
+        None => return false,
+    };
+
+    let sig_bytes = match hex::decode(hex_sig) {
+        Ok(bytes) => bytes,
+        Err(_) => return false,
+    };
+
+    let mut mac = match HmacSha256::new_from_slice(secret.as_bytes()) {
+        Ok(mac) => mac,
+        Err(_) => return false,
+    };
+    mac.update(body);
+
+    // `verify_slice` compares in constant time, so we never leak timing info.
+    mac.verify_slice(&sig_bytes).is_ok()
+}
+
+fn handle_workflow_run_event(owner: &str, repo: &str, payload: &Value) -> Result<(), Box<dyn Error>> {
+    let run_value = payload
+        .get("workflow_run")
+        .ok_or("webhook payload is missing the \"workflow_run\" field")?;
+    let run: WorkflowRun = serde_json::from_value(run_value.clone())?;
+
+    let conclusion_emoji = match run.conclusion.as_deref() {
+        Some("success") => "✅",
+        Some("failure") => "❌",
+        Some(_) => "❓",
+        None => "🔄",
+    };
+
+    println!(
+        "{} {}/{} run {} ({})",
+        conclusion_emoji,
+        owner,
+        repo,
+        run.status,
+        run.conclusion.as_deref().unwrap_or("in progress")
+    );
+
+    Ok(())
+}
+
+fn serve(owner: &str, repo: &str, port: u16, secret: &str) -> Result<(), Box<dyn Error>> {
+    let server = tiny_http::Server::http(format!("0.0.0.0:{}", port))
+        .map_err(|e| format!("failed to bind webhook listener on port {}: {}", port, e))?;
 
     println!(
-        "🔍 Fetching workflows for {}/{}...\n",
-        args.owner, args.repo
+        "📡 Listening for {}/{} workflow_run webhooks on port {}...\n",
+        owner, repo, port
     );
 
-    let response = client
-        .get(format!(
-            "https://api.github.com/repos/{}/{}/actions/workflows",
+    for mut request in server.incoming_requests() {
+        let mut body = Vec::new();
+        if let Err(e) = request.as_reader().read_to_end(&mut body) {
+            eprintln!("⚠️  Failed to read webhook request body: {}", e);
+            continue;
+        }
+
+        let signature = request
+            .headers()
+            .iter()
+            .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case("X-Hub-Signature-256"))
+            .map(|h| h.value.as_str().to_string());
+
+        let verified = signature
+            .as_deref()
+            .is_some_and(|sig| verify_github_signature(secret, &body, sig));
+
+        if !verified {
+            eprintln!("🔒 Rejected webhook delivery: missing or invalid signature");
+            let response = tiny_http::Response::from_string("unauthorized").with_status_code(401);
+            if let Err(e) = request.respond(response) {
+                eprintln!("⚠️  Failed to send webhook response: {}", e);
+            }
+            continue;
+        }
+
+        match serde_json::from_slice::<Value>(&body) {
+            Ok(payload) => {
+                if let Err(e) = handle_workflow_run_event(owner, repo, &payload) {
+                    eprintln!("⚠️  Failed to process webhook payload: {}", e);
+                }
+            }
+            Err(e) => eprintln!("⚠️  Could not parse webhook payload: {}", e),
+        }
+
+        if let Err(e) = request.respond(tiny_http::Response::from_string("ok")) {
+            eprintln!("⚠️  Failed to send webhook response: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    if let Some(Command::Serve { port, secret }) = &args.command {
+        return serve(&args.owner, &args.repo, *port, secret);
+    }
+
+    let client = reqwest::blocking::Client::new();
+
+    if matches!(args.format, OutputFormat::Pretty) {
+        println!(
+            "🔍 Fetching workflows for {}/{}...\n",
             args.owner, args.repo
-        ))
-        .header("User-Agent", "MyApp/1.0")
-        .send()?;
+        );
+    }
 
-    if response.status().is_success() {
-        let json: Value = response.json()?;
+    let range = resolve_date_range(args.since.as_deref(), args.until.as_deref())?;
+    let zone = OutputZone::from_arg(args.timezone);
+    let filter = args.filter.as_deref().map(parse_filter).transpose()?;
 
-        display_workflows(&json, &client, &args.owner, &args.repo)?;
-    } else {
-        println!("❌ Request failed with status: {}", response.status());
+    let mut workflows = fetch_all_workflows(&client, &args.owner, &args.repo, args.limit)?;
+    if let Some(filter) = &filter {
+        workflows.retain(|workflow| filter.matches(workflow));
+    }
+
+    match args.format {
+        OutputFormat::Pretty => {
+            display_workflows(&workflows, &client, &args.owner, &args.repo, range, zone)?
+        }
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            output_structured(&workflows, &client, &args.owner, &args.repo, range, args.format)?
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(created_at: &str) -> WorkflowRun {
+        WorkflowRun {
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+            run_started_at: created_at.to_string(),
+            status: "completed".to_string(),
+            conclusion: Some("success".to_string()),
+        }
+    }
+
+    #[test]
+    fn run_created_in_range_filters_a_realistic_page() {
+        // Mirrors a real `.../actions/workflows/{id}/runs` page: three runs
+        // spanning a day boundary, one of them just outside each end.
+        let response: WorkflowRunsResponse = serde_json::from_value(serde_json::json!({
+            "total_count": 3,
+            "workflow_runs": [
+                { "created_at": "2024-06-09T23:59:00Z", "updated_at": "2024-06-09T23:59:00Z", "run_started_at": "2024-06-09T23:59:00Z", "status": "completed", "conclusion": "success" },
+                { "created_at": "2024-06-10T12:00:00Z", "updated_at": "2024-06-10T12:00:00Z", "run_started_at": "2024-06-10T12:00:00Z", "status": "completed", "conclusion": "success" },
+                { "created_at": "2024-06-11T00:00:00Z", "updated_at": "2024-06-11T00:00:00Z", "run_started_at": "2024-06-11T00:00:00Z", "status": "completed", "conclusion": "success" },
+            ]
+        }))
+        .unwrap();
+
+        let start = parse_github_timestamp("2024-06-10T00:00:00Z").unwrap();
+        let end = parse_github_timestamp("2024-06-11T00:00:00Z").unwrap();
+        let range = (start, end);
+
+        let in_range: Vec<_> = response
+            .workflow_runs
+            .iter()
+            .filter(|r| run_created_in_range(r, range))
+            .collect();
+
+        assert_eq!(in_range.len(), 1);
+        assert_eq!(in_range[0].created_at, "2024-06-10T12:00:00Z");
+    }
+
+    #[test]
+    fn run_created_in_range_rejects_unparseable_timestamp() {
+        let range = (
+            parse_github_timestamp("2024-06-10T00:00:00Z").unwrap(),
+            parse_github_timestamp("2024-06-11T00:00:00Z").unwrap(),
+        );
+        assert!(!run_created_in_range(&run("not-a-timestamp"), range));
+    }
+
+    #[test]
+    fn format_run_duration_picks_the_two_largest_non_zero_units() {
+        let cases = [
+            (0, "0ms"),
+            (250, "250ms"),
+            (1_000, "1s"),
+            (1_030, "1.03s"),
+            (61_000, "1m1s"),
+            (60_000, "1m"),
+            (3_600_000, "1h"),
+            (3_780_000, "1h3m"),
+            (3_605_000, "1h5s"),
+            (3_600_005, "1h5ms"),
+        ];
+
+        for (elapsed_ms, expected) in cases {
+            assert_eq!(format_run_duration(elapsed_ms), expected, "for {}ms", elapsed_ms);
+        }
+    }
+
+    #[test]
+    fn parse_filter_clause_finds_leftmost_operator_not_highest_priority() {
+        let clause = parse_filter_clause(r#"name = "has CONTAINS word""#).unwrap();
+        assert_eq!(clause.field, "name");
+        assert!(matches!(clause.op, FilterOp::Eq));
+        assert_eq!(clause.value, "has CONTAINS word");
+    }
+
+    #[test]
+    fn parse_filter_clause_prefers_longer_operator_over_the_one_inside_it() {
+        let clause = parse_filter_clause(r#"state != "disabled""#).unwrap();
+        assert!(matches!(clause.op, FilterOp::Ne));
+        assert_eq!(clause.value, "disabled");
+    }
+
+    #[test]
+    fn parse_filter_does_not_split_and_or_inside_quoted_values() {
+        let expr = parse_filter(r#"name CONTAINS "Build AND Deploy""#).unwrap();
+        assert_eq!(expr.or_groups.len(), 1);
+        assert_eq!(expr.or_groups[0].len(), 1);
+        assert_eq!(expr.or_groups[0][0].value, "Build AND Deploy");
+    }
+
+    #[test]
+    fn parse_filter_splits_and_or_outside_quotes() {
+        let expr = parse_filter(r#"state = "active" AND name CONTAINS "deploy" OR state = "disabled""#).unwrap();
+        assert_eq!(expr.or_groups.len(), 2);
+        assert_eq!(expr.or_groups[0].len(), 2);
+        assert_eq!(expr.or_groups[1].len(), 1);
+    }
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn verify_github_signature_accepts_a_valid_signature() {
+        let body = b"{\"action\":\"completed\"}";
+        let signature = sign("topsecret", body);
+        assert!(verify_github_signature("topsecret", body, &signature));
+    }
+
+    #[test]
+    fn verify_github_signature_rejects_a_tampered_body() {
+        let signature = sign("topsecret", b"{\"action\":\"completed\"}");
+        assert!(!verify_github_signature("topsecret", b"{\"action\":\"deleted\"}", &signature));
+    }
+
+    #[test]
+    fn verify_github_signature_rejects_the_wrong_secret() {
+        let body = b"{\"action\":\"completed\"}";
+        let signature = sign("topsecret", body);
+        assert!(!verify_github_signature("wrongsecret", body, &signature));
+    }
+
+    #[test]
+    fn verify_github_signature_rejects_a_missing_sha256_prefix() {
+        let body = b"{\"action\":\"completed\"}";
+        let signature = sign("topsecret", body);
+        let bare_hex = signature.strip_prefix("sha256=").unwrap();
+        assert!(!verify_github_signature("topsecret", body, bare_hex));
+    }
+
+    #[test]
+    fn verify_github_signature_rejects_non_hex_signature() {
+        let body = b"{\"action\":\"completed\"}";
+        assert!(!verify_github_signature("topsecret", body, "sha256=not-hex-at-all"));
+    }
+}